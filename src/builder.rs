@@ -0,0 +1,346 @@
+use std::{
+    io::{BufRead, Read},
+    marker::PhantomData,
+    rc::Rc,
+};
+
+use crate::{ByteCSVIter, ByteRow, CSVIter, NoHeader, Position, Row, WithHeader};
+
+/// Controls whether leading/trailing ASCII whitespace is stripped from
+/// unmasked (non-quoted) fields while parsing.
+///
+/// Quoted spans are never trimmed, regardless of mode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TrimMode {
+    /// Leave all fields untouched. This is the default.
+    #[default]
+    None,
+    /// Trim only the header row.
+    Headers,
+    /// Trim only data rows.
+    Fields,
+    /// Trim both the header row and data rows.
+    All,
+}
+
+impl TrimMode {
+    pub(crate) fn trims_headers(self) -> bool {
+        matches!(self, TrimMode::Headers | TrimMode::All)
+    }
+
+    pub(crate) fn trims_fields(self) -> bool {
+        matches!(self, TrimMode::Fields | TrimMode::All)
+    }
+}
+
+/// Collects parser configuration and produces a [`CSVIter`].
+///
+/// Mirrors `new_with_header`/`new_without_header`, but exposes every knob
+/// `parse_row_bytes` understands instead of hard-coding the quote char and
+/// skipping trimming entirely.
+pub struct CSVIterBuilder<R, H = NoHeader> {
+    data: R,
+    delimiter: char,
+    quote: char,
+    trim: TrimMode,
+    has_headers: bool,
+    flexible: bool,
+    _header: PhantomData<H>,
+}
+
+impl<R> CSVIterBuilder<R, NoHeader> {
+    pub fn new(data: R) -> Self {
+        Self {
+            data,
+            delimiter: ',',
+            quote: '"',
+            trim: TrimMode::None,
+            has_headers: false,
+            flexible: false,
+            _header: PhantomData,
+        }
+    }
+
+    /// Whether the first row should be consumed as the header row.
+    ///
+    /// Calling this (with either `true` or `false`) selects the
+    /// `WithHeader` variant of the resulting iterator; `false` still
+    /// produces a `WithHeader` iterator, but with an empty header, the
+    /// same way `new_with_header` behaves for empty input.
+    pub fn has_headers(self, has_headers: bool) -> CSVIterBuilder<R, WithHeader> {
+        CSVIterBuilder {
+            data: self.data,
+            delimiter: self.delimiter,
+            quote: self.quote,
+            trim: self.trim,
+            has_headers,
+            flexible: self.flexible,
+            _header: PhantomData,
+        }
+    }
+}
+
+impl<R, H> CSVIterBuilder<R, H> {
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = delimiter;
+        self
+    }
+
+    pub fn quote(mut self, quote: char) -> Self {
+        self.quote = quote;
+        self
+    }
+
+    pub fn trim(mut self, trim: TrimMode) -> Self {
+        self.trim = trim;
+        self
+    }
+
+    /// Whether rows with a different field count than expected are passed
+    /// through unchanged.
+    ///
+    /// Strict mode (the default, `false`) turns such a mismatch into an
+    /// `InvalidData` error instead: against the header width when a header
+    /// is present, otherwise against the first row's width.
+    pub fn flexible(mut self, flexible: bool) -> Self {
+        self.flexible = flexible;
+        self
+    }
+}
+
+impl<R> CSVIterBuilder<R, NoHeader>
+where
+    R: Read,
+{
+    pub fn build(self) -> CSVIter<R, NoHeader> {
+        CSVIter::new_internal(
+            self.data,
+            NoHeader,
+            self.delimiter,
+            self.quote,
+            self.trim,
+            Position::default(),
+            self.flexible,
+            None,
+        )
+    }
+}
+
+impl<R> CSVIterBuilder<R, NoHeader>
+where
+    R: Read,
+{
+    pub fn build_bytes(self) -> ByteCSVIter<R, NoHeader> {
+        ByteCSVIter::new_internal(
+            self.data,
+            NoHeader,
+            self.delimiter,
+            self.quote,
+            self.trim,
+            Position::default(),
+            self.flexible,
+            None,
+        )
+    }
+}
+
+impl<R> CSVIterBuilder<R, WithHeader>
+where
+    R: BufRead,
+{
+    pub fn build(self) -> std::io::Result<CSVIter<R, WithHeader>> {
+        self.build_with_header(Row::new, WithHeader::new, CSVIter::new_internal)
+    }
+
+    /// Reads the header via `ByteRow` (not `Row`) so a non-UTF-8 header line
+    /// doesn't reject the whole stream before byte mode even starts.
+    pub fn build_bytes(self) -> std::io::Result<ByteCSVIter<R, WithHeader>> {
+        self.build_with_header(ByteRow::new, WithHeader::from_byte_row, ByteCSVIter::new_internal)
+    }
+
+    // Shared by `build`/`build_bytes`: both read the header row the same
+    // way and only differ in which row type `parse_header` returns, how
+    // `into_header` turns that row into a `WithHeader`, and which iterator
+    // type `new_internal` assembles from the result.
+    #[allow(clippy::too_many_arguments)]
+    fn build_with_header<Ro, Out>(
+        mut self,
+        parse_header: impl FnOnce(
+            &mut R,
+            Rc<NoHeader>,
+            char,
+            char,
+            bool,
+            &mut Position,
+        ) -> std::io::Result<Option<Ro>>,
+        into_header: impl FnOnce(Ro) -> WithHeader,
+        new_internal: impl FnOnce(R, WithHeader, char, char, TrimMode, Position, bool, Option<usize>) -> Out,
+    ) -> std::io::Result<Out> {
+        // The header row's own position never becomes visible, so a throwaway
+        // `Position` is fine here; only its byte/line progress carries over.
+        let mut position = Position::default();
+        let header = if self.has_headers {
+            let header_row = parse_header(
+                &mut self.data,
+                Rc::new(NoHeader),
+                self.delimiter,
+                self.quote,
+                self.trim.trims_headers(),
+                &mut position,
+            )?;
+            match header_row {
+                Some(header_row) => into_header(header_row),
+                None => WithHeader::default(),
+            }
+        } else {
+            WithHeader::default()
+        };
+        let expected_width = (!self.flexible && header.width() > 0).then_some(header.width());
+        Ok(new_internal(
+            self.data,
+            header,
+            self.delimiter,
+            self.quote,
+            self.trim,
+            Position {
+                byte: position.byte,
+                line: position.line,
+                record: 0,
+            },
+            self.flexible,
+            expected_width,
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn build_without_header_defaults_match_new_without_header() {
+        let data = "1,2,3";
+        let mut iter = CSVIterBuilder::new(data.as_bytes()).build();
+        let row = iter.next().unwrap().unwrap();
+        assert_eq!(row.get(0), Some("1"));
+        assert_eq!(row.get(1), Some("2"));
+        assert_eq!(row.get(2), Some("3"));
+    }
+
+    #[test]
+    fn build_with_custom_delimiter_quote_and_trim() {
+        let data = "a; 'b c'; d\n1; '2 3'; 4";
+        let mut iter = CSVIterBuilder::new(data.as_bytes())
+            .delimiter(';')
+            .quote('\'')
+            .trim(TrimMode::All)
+            .has_headers(true)
+            .build()
+            .unwrap();
+        let row = iter.next().unwrap().unwrap();
+        assert_eq!(row.get("a"), Some("1"));
+        assert_eq!(row.get("b c"), Some("2 3"));
+        assert_eq!(row.get("d"), Some("4"));
+    }
+
+    #[test]
+    fn build_without_consuming_header_row_yields_empty_header() {
+        let data = "a,b\n1,2";
+        let mut iter = CSVIterBuilder::new(data.as_bytes())
+            .has_headers(false)
+            .build()
+            .unwrap();
+        assert_eq!(iter.width(), 0);
+        let row = iter.next().unwrap().unwrap();
+        assert_eq!(row.get("a"), None);
+    }
+
+    #[test]
+    fn build_bytes_ingests_non_utf8_fields() {
+        let mut data = b"a,b\n".to_vec();
+        data.extend_from_slice(&[0xff, 0xfe, b',', b'2']);
+        let mut iter = CSVIterBuilder::new(&data[..])
+            .has_headers(true)
+            .build_bytes()
+            .unwrap();
+        let row = iter.next().unwrap().unwrap();
+        assert_eq!(row.get_bytes("a"), Some(&[0xff, 0xfe][..]));
+        assert_eq!(row.get("b").unwrap().unwrap(), "2");
+    }
+
+    #[test]
+    fn build_bytes_without_header_defaults_match_new_without_header() {
+        let data = "1,2,3";
+        let mut iter = CSVIterBuilder::new(data.as_bytes()).build_bytes();
+        let row = iter.next().unwrap().unwrap();
+        assert_eq!(row.get(0).unwrap().unwrap(), "1");
+        assert_eq!(row.get(1).unwrap().unwrap(), "2");
+        assert_eq!(row.get(2).unwrap().unwrap(), "3");
+    }
+
+    #[test]
+    fn build_bytes_with_custom_delimiter_quote_and_trim() {
+        let data = "a; 'b c'; d\n1; '2 3'; 4";
+        let mut iter = CSVIterBuilder::new(data.as_bytes())
+            .delimiter(';')
+            .quote('\'')
+            .trim(TrimMode::All)
+            .has_headers(true)
+            .build_bytes()
+            .unwrap();
+        let row = iter.next().unwrap().unwrap();
+        assert_eq!(row.get("a").unwrap().unwrap(), "1");
+        assert_eq!(row.get("b c").unwrap().unwrap(), "2 3");
+        assert_eq!(row.get("d").unwrap().unwrap(), "4");
+    }
+
+    #[test]
+    fn build_bytes_without_consuming_header_row_yields_empty_header() {
+        let data = "a,b\n1,2";
+        let mut iter = CSVIterBuilder::new(data.as_bytes())
+            .has_headers(false)
+            .build_bytes()
+            .unwrap();
+        assert_eq!(iter.width(), 0);
+        let row = iter.next().unwrap().unwrap();
+        assert_eq!(row.get("a"), None);
+    }
+
+    #[test]
+    fn build_bytes_tracks_position_through_header() {
+        let data = "a,b\n1,2\n3,4\n";
+        let mut iter = CSVIterBuilder::new(data.as_bytes())
+            .has_headers(true)
+            .build_bytes()
+            .unwrap();
+        assert_eq!(iter.position(), Position { byte: 4, line: 1, record: 0 });
+        iter.next().unwrap().unwrap();
+        assert_eq!(iter.position(), Position { byte: 8, line: 2, record: 1 });
+    }
+
+    #[test]
+    fn build_bytes_strict_mode_rejects_row_with_wrong_field_count() {
+        let data = "a,b,c\n1,2\n";
+        let mut iter = CSVIterBuilder::new(data.as_bytes())
+            .has_headers(true)
+            .build_bytes()
+            .unwrap();
+        let Err(err) = iter.next().unwrap() else {
+            panic!("expected error");
+        };
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn build_bytes_flexible_mode_passes_mismatched_rows_through() {
+        let data = "a,b,c\n1,2\n";
+        let mut iter = CSVIterBuilder::new(data.as_bytes())
+            .has_headers(true)
+            .flexible(true)
+            .build_bytes()
+            .unwrap();
+        let row = iter.next().unwrap().unwrap();
+        assert_eq!(row.get("a").unwrap().unwrap(), "1");
+        assert_eq!(row.get("b").unwrap().unwrap(), "2");
+    }
+}
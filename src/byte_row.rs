@@ -0,0 +1,313 @@
+use std::{io::BufRead, rc::Rc, str::Utf8Error};
+
+use crate::{NoHeader, Position, WithHeader};
+
+// Byte-oriented counterpart of `Row`. Operates directly on `&[u8]`, so it
+// never rejects input for not being valid UTF-8; `Row` is built on top of
+// this and adds eager UTF-8 validation.
+pub struct ByteRow<H = NoHeader> {
+    pub(crate) data: Vec<Vec<u8>>,
+    header: Rc<H>,
+    position: Position,
+}
+
+impl<H> ByteRow<H> {
+    pub(super) fn new(
+        data: &mut impl BufRead,
+        header: Rc<H>,
+        field_seperator: char,
+        quote: char,
+        trim: bool,
+        position: &mut Position,
+    ) -> std::io::Result<Option<Self>> {
+        let Some(data) = parse_row_bytes(data, field_seperator, quote, trim, position)? else {
+            return Ok(None);
+        };
+        position.advance_record();
+        Ok(Some(Self {
+            data,
+            header,
+            position: *position,
+        }))
+    }
+
+    pub fn position(&self) -> Position {
+        self.position
+    }
+}
+
+impl ByteRow<NoHeader> {
+    pub fn get_bytes(&self, index: usize) -> Option<&[u8]> {
+        self.data.get(index).map(Vec::as_slice)
+    }
+
+    pub fn get(&self, index: usize) -> Option<Result<&str, Utf8Error>> {
+        self.get_bytes(index).map(std::str::from_utf8)
+    }
+}
+
+impl ByteRow<WithHeader> {
+    pub fn get_bytes(&self, key: &str) -> Option<&[u8]> {
+        let index = self.header.get_index(key)?;
+        self.data.get(index).map(Vec::as_slice)
+    }
+
+    pub fn get(&self, key: &str) -> Option<Result<&str, Utf8Error>> {
+        self.get_bytes(key).map(std::str::from_utf8)
+    }
+}
+
+fn parse_row_bytes(
+    data: &mut impl BufRead,
+    field_seperator: char,
+    quote: char,
+    trim: bool,
+    position: &mut Position,
+) -> std::io::Result<Option<Vec<Vec<u8>>>> {
+    // Byte mode compares the separator/quote against raw bytes, so a
+    // multi-byte `char` would silently narrow to its low byte instead of
+    // matching the character the caller configured; reject it loudly.
+    if !field_seperator.is_ascii() || !quote.is_ascii() {
+        return Err(crate::ParseError::new(
+            *position,
+            "Invalid CSV configuration: delimiter and quote must be ASCII in byte mode",
+        )
+        .into_io_error());
+    }
+    let field_seperator = field_seperator as u8;
+    let quote = quote as u8;
+
+    let mut values = Vec::new();
+    let mut buf = Vec::new();
+    let read = data.read_until(b'\n', &mut buf)?;
+    position.advance_bytes(read as u64);
+    if read == 0 {
+        return Ok(None);
+    }
+    let mut idx = 0;
+
+    let mut value_is_masked = false;
+    let mut is_masked_active = false;
+    let mut is_first_byte = true;
+    let mut value_buf: Vec<u8> = Vec::with_capacity(512);
+    let mut last_was_seperator = false;
+
+    while idx < buf.len() {
+        let b = buf[idx];
+        // When trimming, leading whitespace ahead of an opening quote doesn't
+        // disqualify the field from being masked; it's discarded the same
+        // way trimming would discard it anyway, without losing the chance
+        // to recognize the quote that follows it.
+        if trim && is_first_byte && !value_is_masked && b != quote && b.is_ascii_whitespace() {
+            idx += 1;
+            continue;
+        }
+        match (b, value_is_masked, is_first_byte, is_masked_active) {
+            // If the quote byte is the first byte mark the value as masked
+            (b, false, true, false) if b == quote => {
+                value_is_masked = true;
+                is_masked_active = true;
+            }
+            // If the quote byte is not the first byte and the value is not masked
+            (b, false, false, false) if b == quote => {
+                return Err(crate::ParseError::new(
+                    *position,
+                    format!("Invalid CSV data: Unexpected '{}'", quote as char),
+                )
+                .into_io_error());
+            }
+            // If the current value is masked: flip is_masked_active on every quote byte
+            (b, true, false, _) if b == quote => {
+                is_masked_active = !is_masked_active;
+                if is_masked_active {
+                    value_buf.push(quote);
+                }
+            }
+            // If we find a unmasked newline this row is done
+            (b'\n', false, _, _) => {
+                position.advance_line();
+                break;
+            }
+            // If we find a masked newline we need to load the next line
+            (b'\n', true, _, _) => {
+                position.advance_line();
+                value_buf.push(b'\n');
+                buf.clear();
+                let read = data.read_until(b'\n', &mut buf)?;
+                position.advance_bytes(read as u64);
+                idx = 0;
+                continue;
+            }
+            (b, _, _, false) if b == field_seperator => {
+                push_value(&mut values, &mut value_buf, trim && !value_is_masked);
+                is_first_byte = true;
+                value_is_masked = false;
+                is_masked_active = false;
+                last_was_seperator = true;
+                idx += 1;
+                continue;
+            }
+            (b, _, _, _) => {
+                value_buf.push(b);
+            }
+        }
+        is_first_byte = false;
+        last_was_seperator = false;
+        idx += 1;
+    }
+
+    if !value_buf.is_empty() || last_was_seperator {
+        push_value(&mut values, &mut value_buf, trim && !value_is_masked);
+    }
+
+    Ok(Some(values))
+}
+
+fn push_value(values: &mut Vec<Vec<u8>>, value_buf: &mut Vec<u8>, trim: bool) {
+    let mut value = if trim {
+        trim_ascii_whitespace(value_buf).to_vec()
+    } else {
+        value_buf.clone()
+    };
+    value.shrink_to_fit();
+    values.push(value);
+    value_buf.clear();
+}
+
+fn trim_ascii_whitespace(bytes: &[u8]) -> &[u8] {
+    let start = bytes
+        .iter()
+        .position(|b| !b.is_ascii_whitespace())
+        .unwrap_or(bytes.len());
+    let end = bytes
+        .iter()
+        .rposition(|b| !b.is_ascii_whitespace())
+        .map_or(start, |p| p + 1);
+    &bytes[start..end]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn get_value_without_header() {
+        let row = ByteRow {
+            data: vec![b"1".to_vec(), b"2".to_vec(), b"3".to_vec()],
+            header: Rc::new(NoHeader),
+            position: Position::default(),
+        };
+        assert_eq!(row.get_bytes(0), Some(&b"1"[..]));
+        assert_eq!(row.get(1).unwrap().unwrap(), "2");
+        assert_eq!(row.get_bytes(3), None);
+    }
+
+    #[test]
+    fn get_value_invalid_utf8() {
+        let row = ByteRow {
+            data: vec![vec![0xff, 0xfe]],
+            header: Rc::new(NoHeader),
+            position: Position::default(),
+        };
+        assert_eq!(row.get_bytes(0), Some(&[0xff, 0xfe][..]));
+        assert!(row.get(0).unwrap().is_err());
+    }
+
+    #[test]
+    fn parse_single_row_simple() {
+        let data = "field1,field2,field3".to_string();
+        let mut data = data.as_bytes();
+        let row = parse_row_bytes(&mut data, ',', '"', false, &mut Position::default());
+        assert!(row.is_ok());
+        assert_eq!(
+            row.unwrap(),
+            Some(vec![b"field1".to_vec(), b"field2".to_vec(), b"field3".to_vec()])
+        );
+    }
+
+    #[test]
+    fn parse_single_row_simple_end_with_newline() {
+        let data = "field1,field2,field3\n".to_string();
+        let mut data = data.as_bytes();
+        let row = parse_row_bytes(&mut data, ',', '"', false, &mut Position::default());
+        assert!(row.is_ok());
+        assert_eq!(
+            row.unwrap(),
+            Some(vec![b"field1".to_vec(), b"field2".to_vec(), b"field3".to_vec()])
+        );
+    }
+
+    #[test]
+    fn parse_single_row_masked() {
+        let data = r#"field1,"joined,field","quotes""in field""#.to_string();
+        let mut data = data.as_bytes();
+        let row = parse_row_bytes(&mut data, ',', '"', false, &mut Position::default());
+        assert!(row.is_ok());
+        assert_eq!(
+            row.unwrap(),
+            Some(vec![
+                b"field1".to_vec(),
+                b"joined,field".to_vec(),
+                br#"quotes"in field"#.to_vec()
+            ])
+        );
+    }
+
+    #[test]
+    fn parse_multiline_row() {
+        let data = "field1,\"fie\nld2\",\"r1\nr2\"".to_string();
+        let mut data = data.as_bytes();
+        let row = parse_row_bytes(&mut data, ',', '"', false, &mut Position::default());
+        assert!(row.is_ok());
+        assert_eq!(
+            row.unwrap(),
+            Some(vec![
+                b"field1".to_vec(),
+                b"fie\nld2".to_vec(),
+                b"r1\nr2".to_vec()
+            ])
+        );
+    }
+
+    #[test]
+    fn parse_single_row_trims_unmasked_fields_only() {
+        let data = " field1 ,\"  field2  \", field3 ".to_string();
+        let mut data = data.as_bytes();
+        let row = parse_row_bytes(&mut data, ',', '"', true, &mut Position::default());
+        assert!(row.is_ok());
+        assert_eq!(
+            row.unwrap(),
+            Some(vec![
+                b"field1".to_vec(),
+                b"  field2  ".to_vec(),
+                b"field3".to_vec()
+            ])
+        );
+    }
+
+    #[test]
+    fn parse_row_non_utf8_bytes() {
+        let data = [0xff, 0xfe, b',', b'2'];
+        let mut data = &data[..];
+        let row = parse_row_bytes(&mut data, ',', '"', false, &mut Position::default());
+        assert!(row.is_ok());
+        assert_eq!(row.unwrap(), Some(vec![vec![0xff, 0xfe], b"2".to_vec()]));
+    }
+
+    #[test]
+    fn parse_two_rows_tracks_position() {
+        let data = "a,b\nc,d\n".to_string();
+        let mut data = data.as_bytes();
+        let mut position = Position::default();
+        parse_row_bytes(&mut data, ',', '"', false, &mut position)
+            .unwrap()
+            .unwrap();
+        assert_eq!(position.byte, 4);
+        assert_eq!(position.line, 1);
+        parse_row_bytes(&mut data, ',', '"', false, &mut position)
+            .unwrap()
+            .unwrap();
+        assert_eq!(position.byte, 8);
+        assert_eq!(position.line, 2);
+    }
+}
@@ -0,0 +1,104 @@
+use std::io::{self, Read};
+
+/// Which (if any) compression the input stream is wrapped in.
+///
+/// `Compression::None` passes bytes through unchanged; the other variants
+/// require their matching feature flag and decode the stream on the fly as
+/// it's read.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Compression {
+    #[default]
+    None,
+    #[cfg(feature = "gzip")]
+    Gzip,
+    #[cfg(feature = "zstd")]
+    Zstd,
+}
+
+/// Wraps a reader so it transparently decodes whichever [`Compression`] its
+/// data is in.
+///
+/// Gzip decoding is multi-member aware: concatenated `.gz` files (e.g. from
+/// appending exports together) are common, and a single-stream decoder
+/// would silently stop after the first member.
+pub enum CompressedReader<R> {
+    Plain(R),
+    #[cfg(feature = "gzip")]
+    Gzip(flate2::read::MultiGzDecoder<R>),
+    #[cfg(feature = "zstd")]
+    Zstd(zstd::stream::Decoder<'static, io::BufReader<R>>),
+}
+
+impl<R: Read> CompressedReader<R> {
+    pub(crate) fn new(data: R, compression: Compression) -> io::Result<Self> {
+        Ok(match compression {
+            Compression::None => Self::Plain(data),
+            #[cfg(feature = "gzip")]
+            Compression::Gzip => Self::Gzip(flate2::read::MultiGzDecoder::new(data)),
+            #[cfg(feature = "zstd")]
+            Compression::Zstd => Self::Zstd(zstd::stream::Decoder::new(data)?),
+        })
+    }
+}
+
+impl<R: Read> Read for CompressedReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        match self {
+            Self::Plain(r) => r.read(buf),
+            #[cfg(feature = "gzip")]
+            Self::Gzip(r) => r.read(buf),
+            #[cfg(feature = "zstd")]
+            Self::Zstd(r) => r.read(buf),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn plain_reader_passes_bytes_through_unchanged() {
+        let data = b"a,b,c".to_vec();
+        let mut reader = CompressedReader::new(&data[..], Compression::None).unwrap();
+        let mut out = Vec::new();
+        reader.read_to_end(&mut out).unwrap();
+        assert_eq!(out, data);
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn gzip_reader_decodes_concatenated_members() {
+        use std::io::Write;
+
+        fn gzip_member(data: &[u8]) -> Vec<u8> {
+            let mut encoder =
+                flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+            encoder.write_all(data).unwrap();
+            encoder.finish().unwrap()
+        }
+
+        // Two independently-gzipped members concatenated, as produced by
+        // `cat a.csv.gz b.csv.gz > combined.csv.gz`. A single-stream decoder
+        // would stop after the first member and lose the second.
+        let mut combined = gzip_member(b"name,age\nAda,36\n");
+        combined.extend(gzip_member(b"Grace,79\n"));
+
+        let mut reader = CompressedReader::new(&combined[..], Compression::Gzip).unwrap();
+        let mut out = Vec::new();
+        reader.read_to_end(&mut out).unwrap();
+        assert_eq!(out, b"name,age\nAda,36\nGrace,79\n");
+    }
+
+    #[cfg(feature = "zstd")]
+    #[test]
+    fn zstd_reader_round_trips_compressed_data() {
+        let data = b"name,age\nAda,36\n".to_vec();
+        let compressed = zstd::stream::encode_all(&data[..], 0).unwrap();
+
+        let mut reader = CompressedReader::new(&compressed[..], Compression::Zstd).unwrap();
+        let mut out = Vec::new();
+        reader.read_to_end(&mut out).unwrap();
+        assert_eq!(out, data);
+    }
+}
@@ -0,0 +1,273 @@
+use std::fmt;
+
+use serde::de::{
+    self, DeserializeOwned, DeserializeSeed, Deserializer, Error as _, IntoDeserializer,
+    MapAccess, SeqAccess, Visitor,
+};
+
+use crate::{NoHeader, Row, WithHeader};
+
+#[derive(Debug)]
+pub struct Error(String);
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl de::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error(msg.to_string())
+    }
+}
+
+impl Row<WithHeader> {
+    // Maps each of T's fields to a column via the header, visiting columns
+    // in file order; field/column name is what matches.
+    pub fn deserialize<T: DeserializeOwned>(&self) -> Result<T, Error> {
+        T::deserialize(MapRowDeserializer {
+            row: self,
+            keys: self.header().iter().collect::<Vec<_>>().into_iter(),
+            value: None,
+        })
+    }
+}
+
+impl Row<NoHeader> {
+    pub fn deserialize<T: DeserializeOwned>(&self) -> Result<T, Error> {
+        T::deserialize(SeqRowDeserializer { row: self, index: 0 })
+    }
+}
+
+struct MapRowDeserializer<'a> {
+    row: &'a Row<WithHeader>,
+    keys: std::vec::IntoIter<&'a str>,
+    value: Option<Option<&'a str>>,
+}
+
+impl<'de, 'a> Deserializer<'de> for MapRowDeserializer<'a> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_map(self)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+impl<'de, 'a> MapAccess<'de> for MapRowDeserializer<'a> {
+    type Error = Error;
+
+    fn next_key_seed<K: DeserializeSeed<'de>>(
+        &mut self,
+        seed: K,
+    ) -> Result<Option<K::Value>, Error> {
+        let Some(key) = self.keys.next() else {
+            return Ok(None);
+        };
+        self.value = Some(self.row.get(key));
+        seed.deserialize(key.into_deserializer()).map(Some)
+    }
+
+    fn next_value_seed<V: DeserializeSeed<'de>>(&mut self, seed: V) -> Result<V::Value, Error> {
+        match self.value.take() {
+            Some(Some(value)) => seed.deserialize(FieldDeserializer(value)),
+            Some(None) => Err(Error::custom("column has no value for this row")),
+            None => Err(Error::custom("next_value_seed called before next_key_seed")),
+        }
+    }
+}
+
+struct SeqRowDeserializer<'a> {
+    row: &'a Row<NoHeader>,
+    index: usize,
+}
+
+impl<'de, 'a> Deserializer<'de> for SeqRowDeserializer<'a> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_seq(self)
+    }
+
+    serde::forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+impl<'de, 'a> SeqAccess<'de> for SeqRowDeserializer<'a> {
+    type Error = Error;
+
+    fn next_element_seed<T: DeserializeSeed<'de>>(
+        &mut self,
+        seed: T,
+    ) -> Result<Option<T::Value>, Error> {
+        let Some(value) = self.row.get(self.index) else {
+            return Ok(None);
+        };
+        self.index += 1;
+        seed.deserialize(FieldDeserializer(value)).map(Some)
+    }
+}
+
+// Parses `self.0` as `$ty` and hands it to the visitor's matching `visit_*`
+// method, reporting a consistent "invalid value for <type>" error on failure.
+macro_rules! deserialize_number {
+    ($($method:ident => $ty:ty, $visit:ident;)*) => {
+        $(
+            fn $method<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+                visitor.$visit(self.0.parse::<$ty>().map_err(|_| {
+                    Error::custom(format!("invalid value for {}: \"{}\"", stringify!($ty), self.0))
+                })?)
+            }
+        )*
+    };
+}
+
+// Deserializes a single field from its textual representation.
+struct FieldDeserializer<'a>(&'a str);
+
+impl<'de, 'a> Deserializer<'de> for FieldDeserializer<'a> {
+    type Error = Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_str(self.0)
+    }
+
+    deserialize_number! {
+        deserialize_i8 => i8, visit_i8;
+        deserialize_i16 => i16, visit_i16;
+        deserialize_i32 => i32, visit_i32;
+        deserialize_i64 => i64, visit_i64;
+        deserialize_i128 => i128, visit_i128;
+        deserialize_u8 => u8, visit_u8;
+        deserialize_u16 => u16, visit_u16;
+        deserialize_u32 => u32, visit_u32;
+        deserialize_u128 => u128, visit_u128;
+    }
+
+    fn deserialize_bool<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_bool(
+            self.0
+                .parse()
+                .map_err(|_| Error::custom(format!("invalid value for bool: \"{}\"", self.0)))?,
+        )
+    }
+
+    fn deserialize_u64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_u64(
+            self.0
+                .parse()
+                .map_err(|_| Error::custom(format!("invalid value for u64: \"{}\"", self.0)))?,
+        )
+    }
+
+    fn deserialize_f64<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_f64(
+            self.0
+                .parse()
+                .map_err(|_| Error::custom(format!("invalid value for f64: \"{}\"", self.0)))?,
+        )
+    }
+
+    fn deserialize_f32<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_f32(
+            self.0
+                .parse()
+                .map_err(|_| Error::custom(format!("invalid value for f32: \"{}\"", self.0)))?,
+        )
+    }
+
+    fn deserialize_str<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_str(self.0)
+    }
+
+    fn deserialize_string<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        visitor.visit_string(self.0.to_string())
+    }
+
+    fn deserialize_option<V: Visitor<'de>>(self, visitor: V) -> Result<V::Value, Error> {
+        if self.0.is_empty() {
+            visitor.visit_none()
+        } else {
+            visitor.visit_some(self)
+        }
+    }
+
+    serde::forward_to_deserialize_any! {
+        char bytes byte_buf unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use crate::CSVIter;
+
+    #[derive(Deserialize, PartialEq, Debug)]
+    struct Person {
+        name: String,
+        age: u64,
+        nickname: Option<String>,
+    }
+
+    #[test]
+    fn deserialize_row_with_header() {
+        let data = "name,age,nickname\nAda,36,\nGrace,79,Amazing Grace";
+        let mut iter = CSVIter::new_with_header(data.as_bytes(), ',').unwrap();
+        let row = iter.next().unwrap().unwrap();
+        let person: Person = row.deserialize().unwrap();
+        assert_eq!(
+            person,
+            Person {
+                name: "Ada".to_string(),
+                age: 36,
+                nickname: None,
+            }
+        );
+        let row = iter.next().unwrap().unwrap();
+        let person: Person = row.deserialize().unwrap();
+        assert_eq!(
+            person,
+            Person {
+                name: "Grace".to_string(),
+                age: 79,
+                nickname: Some("Amazing Grace".to_string()),
+            }
+        );
+    }
+
+    #[test]
+    fn deserialize_row_without_header_as_tuple() {
+        let data = "1,2.5,true";
+        let mut iter = CSVIter::new_without_header(data.as_bytes(), ',');
+        let row = iter.next().unwrap().unwrap();
+        let tuple: (u64, f64, bool) = row.deserialize().unwrap();
+        assert_eq!(tuple, (1, 2.5, true));
+    }
+
+    #[test]
+    fn deserialize_row_with_default_integer_width() {
+        #[derive(Deserialize, PartialEq, Debug)]
+        struct Rec {
+            age: i32,
+        }
+
+        let data = "age\n36";
+        let mut iter = CSVIter::new_with_header(data.as_bytes(), ',').unwrap();
+        let row = iter.next().unwrap().unwrap();
+        let rec: Rec = row.deserialize().unwrap();
+        assert_eq!(rec, Rec { age: 36 });
+    }
+}
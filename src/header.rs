@@ -1,49 +1,96 @@
-use std::collections::{HashMap, hash_map::Keys};
+use std::{
+    collections::HashMap,
+    iter::Enumerate,
+    slice::Iter,
+};
 
-use crate::Row;
+use crate::{ByteRow, Row};
 
 #[derive(Debug, PartialEq, Eq)]
 pub struct NoHeader;
 
 #[derive(Debug, PartialEq, Eq, Default)]
 pub struct WithHeader {
-    header: HashMap<String, usize>,
+    index: HashMap<String, usize>,
+    names: Vec<String>,
 }
 
 pub struct HeaderIter<'a> {
-    header: Keys<'a, String, usize>
+    names: Iter<'a, String>,
+}
+
+pub struct HeaderEnumerateIter<'a> {
+    names: Enumerate<Iter<'a, String>>,
 }
 
 impl WithHeader {
     pub(super) fn new(header: Row<NoHeader>) -> Self {
-        Self {
-            header: header
-                .data
-                .into_iter()
-                .enumerate()
-                .map(|(i, s)| (s, i))
-                .collect(),
-        }
+        let names = header.data;
+        let index = names
+            .iter()
+            .cloned()
+            .enumerate()
+            .map(|(i, s)| (s, i))
+            .collect();
+        Self { index, names }
+    }
+
+    // Byte-mode counterpart of `new`: the header row may not be valid UTF-8,
+    // so column names are decoded lossily instead of rejecting the row.
+    pub(super) fn from_byte_row(header: ByteRow<NoHeader>) -> Self {
+        let names: Vec<String> = header
+            .data
+            .iter()
+            .map(|field| String::from_utf8_lossy(field).into_owned())
+            .collect();
+        let index = names
+            .iter()
+            .cloned()
+            .enumerate()
+            .map(|(i, s)| (s, i))
+            .collect();
+        Self { index, names }
     }
 
     pub fn get_index(&self, key: &str) -> Option<usize> {
-        self.header.get(key).copied()
+        self.index.get(key).copied()
+    }
+
+    /// The column name at `index`, or `None` if it's out of range.
+    pub fn name_of(&self, index: usize) -> Option<&str> {
+        self.names.get(index).map(String::as_str)
     }
 
-    pub fn iter(&self) -> HeaderIter {
-        let header = self.header.keys();
-        HeaderIter { header }
+    /// Column names in file order (left to right).
+    pub fn iter(&self) -> HeaderIter<'_> {
+        HeaderIter {
+            names: self.names.iter(),
+        }
+    }
+
+    /// Column names in file order, paired with their index.
+    pub fn enumerate(&self) -> HeaderEnumerateIter<'_> {
+        HeaderEnumerateIter {
+            names: self.names.iter().enumerate(),
+        }
     }
 
     pub fn width(&self) -> usize {
-        self.header.len()
+        self.names.len()
     }
 }
 
-impl <'a> Iterator for HeaderIter<'a> {
+impl<'a> Iterator for HeaderIter<'a> {
     type Item = &'a str;
     fn next(&mut self) -> Option<Self::Item> {
-        self.header.next().map(String::as_str)
+        self.names.next().map(String::as_str)
+    }
+}
+
+impl<'a> Iterator for HeaderEnumerateIter<'a> {
+    type Item = (usize, &'a str);
+    fn next(&mut self) -> Option<Self::Item> {
+        self.names.next().map(|(i, s)| (i, s.as_str()))
     }
 }
 
@@ -53,56 +100,91 @@ mod tests {
     use std::rc::Rc;
 
     use super::*;
+    use crate::Position;
 
     #[test]
     fn create_header() {
         let data = "a,b,c".to_string();
         let mut data: &[u8] = data.as_bytes();
-        let header = Row::new(&mut data, Rc::new(NoHeader), ',')
-            .unwrap()
-            .unwrap();
+        let header = Row::new(
+            &mut data,
+            Rc::new(NoHeader),
+            ',',
+            '"',
+            false,
+            &mut Position::default(),
+        )
+        .unwrap()
+        .unwrap();
         assert_eq!(
             WithHeader::new(header),
             WithHeader {
-                header: HashMap::from_iter([
+                index: HashMap::from_iter([
                     ("a".to_string(), 0),
                     ("b".to_string(), 1),
                     ("c".to_string(), 2),
                 ]),
+                names: vec!["a".to_string(), "b".to_string(), "c".to_string()],
             }
         );
     }
 
     #[test]
-    fn header_iter() {
+    fn header_iter_yields_names_in_file_order() {
         let header = WithHeader {
-            header: HashMap::from_iter([
-                "a".to_string(),
-                "b".to_string(),
-                "c".to_string()
-            ].into_iter().enumerate().map(|(a,b)|(b,a)))
+            index: HashMap::from_iter([
+                ("a".to_string(), 0),
+                ("b".to_string(), 1),
+                ("c".to_string(), 2),
+            ]),
+            names: vec!["a".to_string(), "b".to_string(), "c".to_string()],
         };
 
-        let mut expected_keys = vec!["a","b","c"];
+        assert_eq!(header.iter().collect::<Vec<_>>(), vec!["a", "b", "c"]);
+    }
 
-        for key in header.iter() {
-            if expected_keys.contains(&key) {
-                expected_keys.retain(|ek| *ek != key)
-            } else {
-                panic!("found unexpected key: \"{}\"", key)
-            }
-        }
-        assert!(expected_keys.is_empty())
+    #[test]
+    fn header_enumerate_pairs_index_with_name() {
+        let header = WithHeader {
+            index: HashMap::from_iter([
+                ("a".to_string(), 0),
+                ("b".to_string(), 1),
+                ("c".to_string(), 2),
+            ]),
+            names: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+        };
+
+        assert_eq!(
+            header.enumerate().collect::<Vec<_>>(),
+            vec![(0, "a"), (1, "b"), (2, "c")]
+        );
+    }
+
+    #[test]
+    fn header_name_of_looks_up_by_index() {
+        let header = WithHeader {
+            index: HashMap::from_iter([
+                ("a".to_string(), 0),
+                ("b".to_string(), 1),
+                ("c".to_string(), 2),
+            ]),
+            names: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+        };
+
+        assert_eq!(header.name_of(0), Some("a"));
+        assert_eq!(header.name_of(2), Some("c"));
+        assert_eq!(header.name_of(3), None);
     }
 
     #[test]
     fn header_width() {
         let header = WithHeader {
-            header: HashMap::from_iter([
-                "a".to_string(),
-                "b".to_string(),
-                "c".to_string()
-            ].into_iter().enumerate().map(|(a,b)|(b,a)))
+            index: HashMap::from_iter([
+                ("a".to_string(), 0),
+                ("b".to_string(), 1),
+                ("c".to_string(), 2),
+            ]),
+            names: vec!["a".to_string(), "b".to_string(), "c".to_string()],
         };
         assert_eq!(header.width(), 3)
     }
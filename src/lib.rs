@@ -1,33 +1,163 @@
+mod builder;
+mod byte_row;
+mod compression;
+#[cfg(feature = "serde")]
+mod de;
 mod header;
+mod position;
 mod row;
 
+pub use builder::*;
+pub use byte_row::*;
+pub use compression::*;
+#[cfg(feature = "serde")]
+pub use de::*;
 pub use header::*;
+pub use position::*;
 pub use row::*;
 use std::{
-    io::{BufRead, Read},
+    io::{BufRead, BufReader, Read},
     rc::Rc,
 };
 
+// A parsed row's field count and position, the only things `IterState`
+// needs to validate width and advance state regardless of whether the row
+// came back as a `Row` (UTF-8 validated) or a `ByteRow` (raw bytes).
+trait RowMeta {
+    fn field_count(&self) -> usize;
+    fn row_position(&self) -> Position;
+}
+
+impl<H> RowMeta for Row<H> {
+    fn field_count(&self) -> usize {
+        self.data.len()
+    }
+
+    fn row_position(&self) -> Position {
+        self.position()
+    }
+}
+
+impl<H> RowMeta for ByteRow<H> {
+    fn field_count(&self) -> usize {
+        self.data.len()
+    }
+
+    fn row_position(&self) -> Position {
+        self.position()
+    }
+}
+
+// Shared bookkeeping behind `CSVIter`/`ByteCSVIter`: both track the same
+// separator/quote/trim/position/width-validation state and only differ in
+// which row type `next_row`'s `parse` callback hands back.
+struct IterState<H> {
+    header: Rc<H>,
+    separator: char,
+    quote: char,
+    trim: TrimMode,
+    position: Position,
+    flexible: bool,
+    expected_width: Option<usize>,
+}
+
+impl<H> IterState<H> {
+    #[allow(clippy::too_many_arguments)]
+    fn new(
+        header: H,
+        separator: char,
+        quote: char,
+        trim: TrimMode,
+        position: Position,
+        flexible: bool,
+        expected_width: Option<usize>,
+    ) -> Self {
+        Self {
+            header: Rc::new(header),
+            separator,
+            quote,
+            trim,
+            position,
+            flexible,
+            expected_width,
+        }
+    }
+
+    fn position(&self) -> Position {
+        self.position
+    }
+
+    fn next_row<R, Ro>(
+        &mut self,
+        data: &mut R,
+        parse: impl FnOnce(&mut R, Rc<H>, char, char, bool, &mut Position) -> std::io::Result<Option<Ro>>,
+    ) -> Option<std::io::Result<Ro>>
+    where
+        Ro: RowMeta,
+    {
+        let row = match parse(
+            data,
+            self.header.clone(),
+            self.separator,
+            self.quote,
+            self.trim.trims_fields(),
+            &mut self.position,
+        ) {
+            Ok(Some(row)) => row,
+            Ok(None) => return None,
+            Err(err) => return Some(Err(err)),
+        };
+        if let Err(err) = check_width(
+            row.field_count(),
+            row.row_position(),
+            self.flexible,
+            &mut self.expected_width,
+        ) {
+            return Some(Err(err));
+        }
+        Some(Ok(row))
+    }
+}
+
+impl IterState<WithHeader> {
+    fn width(&self) -> usize {
+        self.header.width()
+    }
+}
+
 pub struct CSVIter<R, H = NoHeader>
 where
     R: Read,
 {
-    header: Rc<H>,
+    state: IterState<H>,
     data: R,
-    separator: char,
 }
 
 impl<R, H> CSVIter<R, H>
 where
     R: Read,
 {
-    fn new_internal(data: R, header: H, separator: char) -> Self {
+    #[allow(clippy::too_many_arguments)]
+    fn new_internal(
+        data: R,
+        header: H,
+        separator: char,
+        quote: char,
+        trim: TrimMode,
+        position: Position,
+        flexible: bool,
+        expected_width: Option<usize>,
+    ) -> Self {
         CSVIter {
-            header: Rc::new(header),
+            state: IterState::new(header, separator, quote, trim, position, flexible, expected_width),
             data,
-            separator,
         }
     }
+
+    /// Where the next row will start being read from.
+    pub fn position(&self) -> Position {
+        self.state.position()
+    }
 }
 
 impl<R> CSVIter<R, NoHeader>
@@ -35,7 +165,25 @@ where
     R: Read,
 {
     pub fn new_without_header(data: R, field_separator: char) -> Self {
-        CSVIter::new_internal(data, NoHeader, field_separator)
+        CSVIterBuilder::new(data).delimiter(field_separator).build()
+    }
+}
+
+impl<R> CSVIter<BufReader<CompressedReader<R>>, NoHeader>
+where
+    R: Read,
+{
+    /// Like [`CSVIter::new_without_header`], but transparently decompresses
+    /// `data` first (e.g. a `.csv.gz` export) according to `compression`.
+    pub fn new_without_header_compressed(
+        data: R,
+        field_separator: char,
+        compression: Compression,
+    ) -> std::io::Result<Self> {
+        let data = BufReader::new(CompressedReader::new(data, compression)?);
+        Ok(CSVIterBuilder::new(data)
+            .delimiter(field_separator)
+            .build())
     }
 }
 
@@ -43,19 +191,34 @@ impl<R> CSVIter<R, WithHeader>
 where
     R: BufRead,
 {
-    pub fn new_with_header(mut data: R, field_separator: char) -> std::io::Result<Self> {
-        let header = Row::new(&mut data, Rc::new(NoHeader), field_separator)?;
-        let header = if let Some(header_row) = header
-        {
-            WithHeader::new(header_row)
-        } else {
-            WithHeader::default()
-        };
-        Ok(CSVIter::new_internal(data, header, field_separator))
+    pub fn new_with_header(data: R, field_separator: char) -> std::io::Result<Self> {
+        CSVIterBuilder::new(data)
+            .delimiter(field_separator)
+            .has_headers(true)
+            .build()
     }
 
     pub fn width(&self) -> usize {
-        self.header.width()
+        self.state.width()
+    }
+}
+
+impl<R> CSVIter<BufReader<CompressedReader<R>>, WithHeader>
+where
+    R: Read,
+{
+    /// Like [`CSVIter::new_with_header`], but transparently decompresses
+    /// `data` first (e.g. a `.csv.gz` export) according to `compression`.
+    pub fn new_with_header_compressed(
+        data: R,
+        field_separator: char,
+        compression: Compression,
+    ) -> std::io::Result<Self> {
+        let data = BufReader::new(CompressedReader::new(data, compression)?);
+        CSVIterBuilder::new(data)
+            .delimiter(field_separator)
+            .has_headers(true)
+            .build()
     }
 }
 
@@ -66,7 +229,145 @@ where
     type Item = std::io::Result<Row<H>>;
 
     fn next(&mut self) -> Option<Self::Item> {
-        Row::new(&mut self.data, self.header.clone(), self.separator).transpose()
+        self.state.next_row(&mut self.data, Row::new)
+    }
+}
+
+// Checks a parsed row's field count against the width strict mode expects,
+// pinning that width to the first row seen when none is set yet.
+fn check_width(
+    row_width: usize,
+    position: Position,
+    flexible: bool,
+    expected_width: &mut Option<usize>,
+) -> std::io::Result<()> {
+    if flexible {
+        return Ok(());
+    }
+    match *expected_width {
+        Some(expected) if expected != row_width => Err(ParseError::new(
+            position,
+            format!("row has {row_width} fields, expected {expected}"),
+        )
+        .into_io_error()),
+        Some(_) => Ok(()),
+        None => {
+            *expected_width = Some(row_width);
+            Ok(())
+        }
+    }
+}
+
+// Byte-oriented counterpart of `CSVIter`, yielding `ByteRow` instead of
+// `Row` so input that isn't valid UTF-8 can still be ingested.
+pub struct ByteCSVIter<R, H = NoHeader>
+where
+    R: Read,
+{
+    state: IterState<H>,
+    data: R,
+}
+
+impl<R, H> ByteCSVIter<R, H>
+where
+    R: Read,
+{
+    #[allow(clippy::too_many_arguments)]
+    fn new_internal(
+        data: R,
+        header: H,
+        separator: char,
+        quote: char,
+        trim: TrimMode,
+        position: Position,
+        flexible: bool,
+        expected_width: Option<usize>,
+    ) -> Self {
+        ByteCSVIter {
+            state: IterState::new(header, separator, quote, trim, position, flexible, expected_width),
+            data,
+        }
+    }
+
+    /// Where the next row will start being read from.
+    pub fn position(&self) -> Position {
+        self.state.position()
+    }
+}
+
+impl<R> ByteCSVIter<R, NoHeader>
+where
+    R: Read,
+{
+    pub fn new_without_header(data: R, field_separator: char) -> Self {
+        CSVIterBuilder::new(data)
+            .delimiter(field_separator)
+            .build_bytes()
+    }
+}
+
+impl<R> ByteCSVIter<BufReader<CompressedReader<R>>, NoHeader>
+where
+    R: Read,
+{
+    /// Like [`ByteCSVIter::new_without_header`], but transparently
+    /// decompresses `data` first (e.g. a `.csv.gz` export) according to
+    /// `compression`.
+    pub fn new_without_header_compressed(
+        data: R,
+        field_separator: char,
+        compression: Compression,
+    ) -> std::io::Result<Self> {
+        let data = BufReader::new(CompressedReader::new(data, compression)?);
+        Ok(CSVIterBuilder::new(data)
+            .delimiter(field_separator)
+            .build_bytes())
+    }
+}
+
+impl<R> ByteCSVIter<R, WithHeader>
+where
+    R: BufRead,
+{
+    pub fn new_with_header(data: R, field_separator: char) -> std::io::Result<Self> {
+        CSVIterBuilder::new(data)
+            .delimiter(field_separator)
+            .has_headers(true)
+            .build_bytes()
+    }
+
+    pub fn width(&self) -> usize {
+        self.state.width()
+    }
+}
+
+impl<R> ByteCSVIter<BufReader<CompressedReader<R>>, WithHeader>
+where
+    R: Read,
+{
+    /// Like [`ByteCSVIter::new_with_header`], but transparently decompresses
+    /// `data` first (e.g. a `.csv.gz` export) according to `compression`.
+    pub fn new_with_header_compressed(
+        data: R,
+        field_separator: char,
+        compression: Compression,
+    ) -> std::io::Result<Self> {
+        let data = BufReader::new(CompressedReader::new(data, compression)?);
+        CSVIterBuilder::new(data)
+            .delimiter(field_separator)
+            .has_headers(true)
+            .build_bytes()
+    }
+}
+
+impl<R, H> Iterator for ByteCSVIter<R, H>
+where
+    R: BufRead,
+{
+    type Item = std::io::Result<ByteRow<H>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.state.next_row(&mut self.data, ByteRow::new)
     }
 }
 
@@ -78,7 +379,7 @@ mod tests {
     fn create_iter_without_header() {
         let data = "1,2,3\n4,5,6\n7,8,9";
         let mut iter = CSVIter::new_without_header(data.as_bytes(), ',');
-        assert_eq!(iter.header, Rc::new(NoHeader));
+        assert_eq!(iter.state.header, Rc::new(NoHeader));
         assert_eq!(iter.data, data.as_bytes());
         let row = iter.next().unwrap().unwrap();
         assert_eq!(row.get(0), Some("1"));
@@ -99,20 +400,20 @@ mod tests {
     fn create_iter_with_header() {
         let data = "a,b,c\n1,2,3\n4,5,6\n7,8,9";
         let mut iter = CSVIter::new_with_header(data.as_bytes(), ',').unwrap();
-        assert_eq!(iter.data, data[6..].as_bytes());
+        assert_eq!(iter.data, &data.as_bytes()[6..]);
         let row = iter.next();
         let row = row.unwrap().unwrap();
-        assert_eq!(row.get_by_key("a"), Some("1"));
-        assert_eq!(row.get_by_key("b"), Some("2"));
-        assert_eq!(row.get_by_key("c"), Some("3"));
+        assert_eq!(row.get("a"), Some("1"));
+        assert_eq!(row.get("b"), Some("2"));
+        assert_eq!(row.get("c"), Some("3"));
         let row = iter.next().unwrap().unwrap();
-        assert_eq!(row.get_by_key("a"), Some("4"));
-        assert_eq!(row.get_by_key("b"), Some("5"));
-        assert_eq!(row.get_by_key("c"), Some("6"));
+        assert_eq!(row.get("a"), Some("4"));
+        assert_eq!(row.get("b"), Some("5"));
+        assert_eq!(row.get("c"), Some("6"));
         let row = iter.next().unwrap().unwrap();
-        assert_eq!(row.get_by_key("a"), Some("7"));
-        assert_eq!(row.get_by_key("b"), Some("8"));
-        assert_eq!(row.get_by_key("c"), Some("9"));
+        assert_eq!(row.get("a"), Some("7"));
+        assert_eq!(row.get("b"), Some("8"));
+        assert_eq!(row.get("c"), Some("9"));
         assert!(iter.next().is_none());
         assert_eq!(iter.width(), 3)
     }
@@ -124,6 +425,68 @@ mod tests {
         assert!(iter.next().is_none());
     }
 
+    #[test]
+    fn iter_tracks_position_across_rows() {
+        let data = "1,2,3\n4,5,6\n";
+        let mut iter = CSVIter::new_without_header(data.as_bytes(), ',');
+        assert_eq!(iter.position(), Position::default());
+        iter.next().unwrap().unwrap();
+        assert_eq!(
+            iter.position(),
+            Position { byte: 6, line: 1, record: 1 }
+        );
+        iter.next().unwrap().unwrap();
+        assert_eq!(
+            iter.position(),
+            Position { byte: 12, line: 2, record: 2 }
+        );
+    }
+
+    #[test]
+    fn strict_mode_rejects_row_with_wrong_field_count() {
+        let data = "a,b,c\n1,2\n";
+        let mut iter = CSVIter::new_with_header(data.as_bytes(), ',').unwrap();
+        let Err(err) = iter.next().unwrap() else {
+            panic!("expected error");
+        };
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn flexible_mode_passes_mismatched_rows_through() {
+        let data = "a,b,c\n1,2\n";
+        let mut iter = CSVIterBuilder::new(data.as_bytes())
+            .has_headers(true)
+            .flexible(true)
+            .build()
+            .unwrap();
+        let row = iter.next().unwrap().unwrap();
+        assert_eq!(row.get("a"), Some("1"));
+        assert_eq!(row.get("b"), Some("2"));
+    }
+
+    #[test]
+    fn strict_mode_without_header_pins_width_to_first_row() {
+        let data = "1,2,3\n4,5\n";
+        let mut iter = CSVIter::new_without_header(data.as_bytes(), ',');
+        iter.next().unwrap().unwrap();
+        let Err(err) = iter.next().unwrap() else {
+            panic!("expected error");
+        };
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn new_with_header_compressed_passes_through_uncompressed_data() {
+        let data = "a,b,c\n1,2,3\n";
+        let mut iter =
+            CSVIter::new_with_header_compressed(data.as_bytes(), ',', Compression::None).unwrap();
+        let row = iter.next().unwrap().unwrap();
+        assert_eq!(row.get("a"), Some("1"));
+        assert_eq!(row.get("b"), Some("2"));
+        assert_eq!(row.get("c"), Some("3"));
+    }
+
     #[test]
     fn invalid_csv() {
         let data = b"test,invalid\"value\nvalid,invalid\"\"value2";
@@ -136,4 +499,144 @@ mod tests {
             assert_eq!(e.kind(), std::io::ErrorKind::InvalidData);
         }
     }
+
+    #[test]
+    fn invalid_csv_error_carries_position() {
+        let data = b"valid,row\ntest,invalid\"value";
+        let mut csv = CSVIter::new_without_header(&data[..], ',');
+
+        csv.next().unwrap().unwrap();
+        let Some(Err(err)) = csv.next() else {
+            panic!("Expected error");
+        };
+        let parse_err = err
+            .get_ref()
+            .unwrap()
+            .downcast_ref::<ParseError>()
+            .unwrap();
+        assert_eq!(parse_err.position, Position { byte: 28, line: 1, record: 1 });
+    }
+
+    #[test]
+    fn byte_create_iter_without_header() {
+        let data = "1,2,3\n4,5,6\n7,8,9";
+        let mut iter = ByteCSVIter::new_without_header(data.as_bytes(), ',');
+        assert_eq!(iter.state.header, Rc::new(NoHeader));
+        assert_eq!(iter.data, data.as_bytes());
+        let row = iter.next().unwrap().unwrap();
+        assert_eq!(row.get(0).unwrap().unwrap(), "1");
+        assert_eq!(row.get(1).unwrap().unwrap(), "2");
+        assert_eq!(row.get(2).unwrap().unwrap(), "3");
+        let row = iter.next().unwrap().unwrap();
+        assert_eq!(row.get(0).unwrap().unwrap(), "4");
+        assert_eq!(row.get(1).unwrap().unwrap(), "5");
+        assert_eq!(row.get(2).unwrap().unwrap(), "6");
+        let row = iter.next().unwrap().unwrap();
+        assert_eq!(row.get(0).unwrap().unwrap(), "7");
+        assert_eq!(row.get(1).unwrap().unwrap(), "8");
+        assert_eq!(row.get(2).unwrap().unwrap(), "9");
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn byte_create_iter_with_header() {
+        let data = "a,b,c\n1,2,3\n4,5,6\n7,8,9";
+        let mut iter = ByteCSVIter::new_with_header(data.as_bytes(), ',').unwrap();
+        assert_eq!(iter.data, &data.as_bytes()[6..]);
+        let row = iter.next().unwrap().unwrap();
+        assert_eq!(row.get("a").unwrap().unwrap(), "1");
+        assert_eq!(row.get("b").unwrap().unwrap(), "2");
+        assert_eq!(row.get("c").unwrap().unwrap(), "3");
+        let row = iter.next().unwrap().unwrap();
+        assert_eq!(row.get("a").unwrap().unwrap(), "4");
+        assert_eq!(row.get("b").unwrap().unwrap(), "5");
+        assert_eq!(row.get("c").unwrap().unwrap(), "6");
+        let row = iter.next().unwrap().unwrap();
+        assert_eq!(row.get("a").unwrap().unwrap(), "7");
+        assert_eq!(row.get("b").unwrap().unwrap(), "8");
+        assert_eq!(row.get("c").unwrap().unwrap(), "9");
+        assert!(iter.next().is_none());
+        assert_eq!(iter.width(), 3)
+    }
+
+    #[test]
+    fn byte_create_iter_with_header_default() {
+        let mut iter = ByteCSVIter::new_with_header("".as_bytes(), ',').unwrap();
+        assert_eq!(iter.width(), 0);
+        assert!(iter.next().is_none());
+    }
+
+    #[test]
+    fn byte_iter_tracks_position_across_rows() {
+        let data = "1,2,3\n4,5,6\n";
+        let mut iter = ByteCSVIter::new_without_header(data.as_bytes(), ',');
+        assert_eq!(iter.position(), Position::default());
+        iter.next().unwrap().unwrap();
+        assert_eq!(
+            iter.position(),
+            Position { byte: 6, line: 1, record: 1 }
+        );
+        iter.next().unwrap().unwrap();
+        assert_eq!(
+            iter.position(),
+            Position { byte: 12, line: 2, record: 2 }
+        );
+    }
+
+    #[test]
+    fn byte_strict_mode_rejects_row_with_wrong_field_count() {
+        let data = "a,b,c\n1,2\n";
+        let mut iter = ByteCSVIter::new_with_header(data.as_bytes(), ',').unwrap();
+        let Err(err) = iter.next().unwrap() else {
+            panic!("expected error");
+        };
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn byte_flexible_mode_passes_mismatched_rows_through() {
+        let data = "a,b,c\n1,2\n";
+        let mut iter = CSVIterBuilder::new(data.as_bytes())
+            .has_headers(true)
+            .flexible(true)
+            .build_bytes()
+            .unwrap();
+        let row = iter.next().unwrap().unwrap();
+        assert_eq!(row.get("a").unwrap().unwrap(), "1");
+        assert_eq!(row.get("b").unwrap().unwrap(), "2");
+    }
+
+    #[test]
+    fn byte_strict_mode_without_header_pins_width_to_first_row() {
+        let data = "1,2,3\n4,5\n";
+        let mut iter = ByteCSVIter::new_without_header(data.as_bytes(), ',');
+        iter.next().unwrap().unwrap();
+        let Err(err) = iter.next().unwrap() else {
+            panic!("expected error");
+        };
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn byte_new_with_header_compressed_passes_through_uncompressed_data() {
+        let data = "a,b,c\n1,2,3\n";
+        let mut iter = ByteCSVIter::new_with_header_compressed(data.as_bytes(), ',', Compression::None)
+            .unwrap();
+        let row = iter.next().unwrap().unwrap();
+        assert_eq!(row.get("a").unwrap().unwrap(), "1");
+        assert_eq!(row.get("b").unwrap().unwrap(), "2");
+        assert_eq!(row.get("c").unwrap().unwrap(), "3");
+    }
+
+    #[test]
+    fn byte_new_without_header_compressed_passes_through_uncompressed_data() {
+        let data = "1,2,3\n";
+        let mut iter =
+            ByteCSVIter::new_without_header_compressed(data.as_bytes(), ',', Compression::None)
+                .unwrap();
+        let row = iter.next().unwrap().unwrap();
+        assert_eq!(row.get(0).unwrap().unwrap(), "1");
+        assert_eq!(row.get(1).unwrap().unwrap(), "2");
+        assert_eq!(row.get(2).unwrap().unwrap(), "3");
+    }
 }
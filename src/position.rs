@@ -0,0 +1,62 @@
+use std::fmt;
+
+// Where a row (or a parse failure) sits in the underlying stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Position {
+    pub byte: u64,
+    pub line: u64,
+    pub record: u64,
+}
+
+impl Position {
+    pub(crate) fn advance_bytes(&mut self, bytes: u64) {
+        self.byte += bytes;
+    }
+
+    pub(crate) fn advance_line(&mut self) {
+        self.line += 1;
+    }
+
+    pub(crate) fn advance_record(&mut self) {
+        self.record += 1;
+    }
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}, byte {}, record {}",
+            self.line, self.byte, self.record
+        )
+    }
+}
+
+// Wrapped inside the `io::Error` returned by this crate's parsers so callers
+// can tell where in the stream a parse failure happened.
+#[derive(Debug)]
+pub struct ParseError {
+    pub position: Position,
+    message: String,
+}
+
+impl ParseError {
+    pub(crate) fn new(position: Position, message: impl Into<String>) -> Self {
+        Self {
+            position,
+            message: message.into(),
+        }
+    }
+
+    pub(crate) fn into_io_error(self) -> std::io::Error {
+        std::io::Error::new(std::io::ErrorKind::InvalidData, self)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.message, self.position)
+    }
+}
+
+impl std::error::Error for ParseError {}
@@ -1,10 +1,13 @@
 use std::{io::BufRead, rc::Rc};
 
-use crate::{NoHeader, WithHeader};
+use crate::{ByteRow, NoHeader, ParseError, Position, WithHeader};
 
+// Thin, validated wrapper over `ByteRow`: same state machine, but every
+// field is checked to be valid UTF-8 up front so `get` can hand out `&str`.
 pub struct Row<H = NoHeader> {
     pub(crate) data: Vec<String>,
     header: Rc<H>,
+    position: Position,
 }
 
 impl<H> Row<H> {
@@ -12,11 +15,40 @@ impl<H> Row<H> {
         data: &mut impl BufRead,
         header: Rc<H>,
         field_seperator: char,
+        quote: char,
+        trim: bool,
+        position: &mut Position,
     ) -> std::io::Result<Option<Self>> {
-        let Some(data) = parse_row(data, field_seperator)? else {
+        let Some(byte_row) =
+            ByteRow::new(data, header.clone(), field_seperator, quote, trim, position)?
+        else {
             return Ok(None);
         };
-        Ok(Some(Self { data, header }))
+        let row_position = byte_row.position();
+        let data = byte_row
+            .data
+            .into_iter()
+            .map(|field| {
+                String::from_utf8(field).map_err(|err| {
+                    ParseError::new(row_position, format!("Invalid CSV data: {err}"))
+                        .into_io_error()
+                })
+            })
+            .collect::<std::io::Result<Vec<_>>>()?;
+        Ok(Some(Self {
+            data,
+            header,
+            position: row_position,
+        }))
+    }
+
+    pub fn position(&self) -> Position {
+        self.position
+    }
+
+    #[cfg(feature = "serde")]
+    pub(crate) fn header(&self) -> &H {
+        &self.header
     }
 }
 
@@ -33,82 +65,6 @@ impl Row<WithHeader> {
     }
 }
 
-fn parse_row(
-    data: &mut impl BufRead,
-    field_seperator: char,
-) -> std::io::Result<Option<Vec<String>>> {
-    let mut values = Vec::new();
-    let mut buf = String::new();
-    if data.read_line(&mut buf)? == 0 {
-        return Ok(None);
-    };
-    let mut chars = buf.chars();
-
-    let mut value_is_masked = false;
-    let mut is_masked_active = false;
-    let mut is_first_char = true;
-    let mut value_buf = String::with_capacity(512);
-    let mut last_was_seperator = false;
-
-    while let Some(c) = chars.next() {
-        match (c, value_is_masked, is_first_char, is_masked_active) {
-            // If '"' is the first char mark the value as masked
-            ('"', false, true, false) => {
-                value_is_masked = true;
-                is_masked_active = true;
-            }
-            // If '"' is not the first char and the value is not masked
-            ('"', false, false, false) => {
-                return Err(std::io::Error::new(
-                    std::io::ErrorKind::InvalidData,
-                    "Invalid CSV data: Unexpected '\"'",
-                ));
-            }
-            // If the current value is masked: flip is_masked_active on every '"'
-            ('"', true, false, _) => {
-                is_masked_active = !is_masked_active;
-                if is_masked_active {
-                    value_buf.push('"');
-                }
-            }
-            // If we find a unmasked newline this row is done
-            ('\n', false, _, _) => {
-                break;
-            }
-            // If we find a masked newline we need to load the next line
-            ('\n', true, _, _) => {
-                value_buf.push('\n');
-                buf.clear();
-                data.read_line(&mut buf)?;
-                chars = buf.chars();
-            }
-            (c, _, _, false) if c == field_seperator => {
-                let mut value = value_buf.clone();
-                value.shrink_to_fit();
-                values.push(value);
-
-                value_buf.clear();
-                is_first_char = true;
-                value_is_masked = false;
-                is_masked_active = false;
-                last_was_seperator = true;
-                continue;
-            }
-            (c, _, _, _) => {
-                value_buf.push(c);
-            }
-        }
-        is_first_char = false;
-        last_was_seperator = false;
-    }
-
-    if !value_buf.is_empty() || last_was_seperator {
-        values.push(value_buf);
-    }
-
-    Ok(Some(values))
-}
-
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -118,6 +74,7 @@ mod tests {
         let row = Row {
             data: vec!["1".to_string(), "2".to_string(), "3".to_string()],
             header: Rc::new(NoHeader),
+            position: Position::default(),
         };
         assert_eq!(row.get(0), Some("1"));
         assert_eq!(row.get(1), Some("2"));
@@ -126,98 +83,53 @@ mod tests {
     }
 
     #[test]
-    fn parse_single_row_simple() {
+    fn new_parses_simple_row() {
         let data = "field1,field2,field3".to_string();
         let mut data = data.as_bytes();
-        let row = parse_row(&mut data, ',');
-        assert!(row.is_ok());
-        assert_eq!(
-            row.unwrap(),
-            Some(vec![
-                "field1".to_string(),
-                "field2".to_string(),
-                "field3".to_string()
-            ])
-        );
-    }
-
-    #[test]
-    fn parse_single_row_simple_end_with_newline() {
-        let data = "field1,field2,field3\n".to_string();
-        let mut data = data.as_bytes();
-        let row = parse_row(&mut data, ',');
-        assert!(row.is_ok());
-        assert_eq!(
-            row.unwrap(),
-            Some(vec![
-                "field1".to_string(),
-                "field2".to_string(),
-                "field3".to_string()
-            ])
-        );
+        let row = Row::new(&mut data, Rc::new(NoHeader), ',', '"', false, &mut Position::default())
+            .unwrap()
+            .unwrap();
+        assert_eq!(row.get(0), Some("field1"));
+        assert_eq!(row.get(1), Some("field2"));
+        assert_eq!(row.get(2), Some("field3"));
     }
 
     #[test]
-    fn parse_single_row_masked() {
+    fn new_parses_masked_row() {
         let data = r#"field1,"joined,field","quotes""in field""#.to_string();
         let mut data = data.as_bytes();
-        let row = parse_row(&mut data, ',');
-        assert!(row.is_ok());
-        assert_eq!(
-            row.unwrap(),
-            Some(vec![
-                "field1".to_string(),
-                "joined,field".to_string(),
-                r#"quotes"in field"#.to_string()
-            ])
-        );
+        let row = Row::new(&mut data, Rc::new(NoHeader), ',', '"', false, &mut Position::default())
+            .unwrap()
+            .unwrap();
+        assert_eq!(row.get(0), Some("field1"));
+        assert_eq!(row.get(1), Some("joined,field"));
+        assert_eq!(row.get(2), Some(r#"quotes"in field"#));
     }
 
     #[test]
-    fn parse_single_row_empty_value() {
-        let data = "field1,,field3".to_string();
-        let mut data = data.as_bytes();
-        let row = parse_row(&mut data, ',');
-        assert!(row.is_ok());
-        assert_eq!(
-            row.unwrap(),
-            Some(vec![
-                "field1".to_string(),
-                "".to_string(),
-                "field3".to_string()
-            ])
-        );
-    }
-
-    #[test]
-    fn parse_single_row_empty_value_at_end() {
-        let data = "field1,field2,".to_string();
-        let mut data = data.as_bytes();
-        let row = parse_row(&mut data, ',');
-        assert!(row.is_ok());
-        assert_eq!(
-            row.unwrap(),
-            Some(vec![
-                "field1".to_string(),
-                "field2".to_string(),
-                "".to_string()
-            ])
-        );
+    fn new_rejects_invalid_utf8() {
+        let data = [0xff, 0xfe, b',', b'2'];
+        let mut data = &data[..];
+        let Err(err) =
+            Row::new(&mut data, Rc::new(NoHeader), ',', '"', false, &mut Position::default())
+        else {
+            panic!("expected error");
+        };
+        assert_eq!(err.kind(), std::io::ErrorKind::InvalidData);
     }
 
     #[test]
-    fn parse_multiline_row() {
-        let data = "field1,\"fie\nld2\",\"r1\nr2\"".to_string();
+    fn new_tracks_position() {
+        let data = "a,b\nc,d\n".to_string();
         let mut data = data.as_bytes();
-        let row = parse_row(&mut data, ',');
-        assert!(row.is_ok());
-        assert_eq!(
-            row.unwrap(),
-            Some(vec![
-                "field1".to_string(),
-                "fie\nld2".to_string(),
-                "r1\nr2".to_string()
-            ])
-        );
+        let mut position = Position::default();
+        let row = Row::new(&mut data, Rc::new(NoHeader), ',', '"', false, &mut position)
+            .unwrap()
+            .unwrap();
+        assert_eq!(row.position(), Position { byte: 4, line: 1, record: 1 });
+        let row = Row::new(&mut data, Rc::new(NoHeader), ',', '"', false, &mut position)
+            .unwrap()
+            .unwrap();
+        assert_eq!(row.position(), Position { byte: 8, line: 2, record: 2 });
     }
 }